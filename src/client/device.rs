@@ -0,0 +1,140 @@
+//! OAuth 2.0 Device Authorization Grant.
+//!
+//! See [RFC 8628](http://tools.ietf.org/html/rfc8628).
+
+use std::io::Read;
+use std::thread;
+use std::time::Duration as StdDuration;
+
+use hyper::client::Client as HttpClient;
+use hyper::header::{Accept, ContentType};
+use rustc_serialize::json::Json;
+use url::form_urlencoded;
+
+use client::{Client, Error};
+use client::response::{FromResponse, ObjectHelper, JsonHelper, OAuthError, OAuthErrorCode, ParseError};
+use provider::Provider;
+use token::Scopes;
+
+/// The response to a device authorization request.
+///
+/// See [RFC 8628, section 3.2](http://tools.ietf.org/html/rfc8628#section-3.2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceCode {
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    verification_uri_complete: Option<String>,
+    expires_in: i64,
+    interval: i64,
+}
+
+impl DeviceCode {
+    /// Returns the device verification code.
+    pub fn device_code(&self) -> &str { &self.device_code }
+
+    /// Returns the end-user verification code, to be displayed to the user.
+    pub fn user_code(&self) -> &str { &self.user_code }
+
+    /// Returns the end-user verification URI on the authorization server.
+    pub fn verification_uri(&self) -> &str { &self.verification_uri }
+
+    /// Returns a verification URI that includes the `user_code`, if the provider supports it.
+    pub fn verification_uri_complete(&self) -> Option<&str> {
+        self.verification_uri_complete.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns the lifetime, in seconds, of the `device_code` and `user_code`.
+    pub fn expires_in(&self) -> i64 { self.expires_in }
+
+    /// Returns the minimum amount of time, in seconds, the client should wait between polling
+    /// requests to the token endpoint.
+    pub fn interval(&self) -> i64 { self.interval }
+}
+
+impl FromResponse for DeviceCode {
+    fn from_response(json: &Json) -> Result<Self, ParseError> {
+        let obj = try!(JsonHelper(json).as_object());
+
+        Ok(DeviceCode {
+            device_code: try!(obj.get_string("device_code")).into(),
+            user_code: try!(obj.get_string("user_code")).into(),
+            verification_uri: try!(obj.get_string("verification_uri")).into(),
+            verification_uri_complete: obj.get_string_option("verification_uri_complete").map(Into::into),
+            expires_in: try!(obj.get_i64("expires_in")),
+            interval: obj.get_i64_option("interval").unwrap_or(5),
+        })
+    }
+}
+
+/// The grant type used when polling the token endpoint for a device code.
+const GRANT_TYPE: &'static str = "urn:ietf:params:oauth:grant-type:device_code";
+
+impl<P: Provider> Client<P> {
+    /// Requests a `DeviceCode` from the provider's device authorization endpoint.
+    ///
+    /// See [RFC 8628, section 3.1](http://tools.ietf.org/html/rfc8628#section-3.1).
+    pub fn device_code(&self, http_client: &HttpClient, scope: Option<&Scopes>) -> Result<DeviceCode, Error> {
+        let device_uri = match self.provider.device_uri() {
+            Some(uri) => uri,
+            None => return Err(Error::Parse(ParseError::ExpectedField("device_authorization_endpoint"))),
+        };
+
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        serializer.append_pair("client_id", &self.client_id);
+        if let Some(scope) = scope {
+            serializer.append_pair("scope", scope.as_str());
+        }
+        let body = serializer.finish();
+
+        let mut response = try!(
+            http_client.post(device_uri)
+                .header(ContentType::form_url_encoded())
+                .header(Accept::json())
+                .body(&body)
+                .send()
+        );
+        let mut response_body = String::new();
+        try!(response.read_to_string(&mut response_body));
+
+        let json = try!(Json::from_str(&response_body));
+        Ok(try!(DeviceCode::from_response(&json)))
+    }
+
+    /// Polls the token endpoint until the user has completed the device authorization flow,
+    /// honoring the RFC 8628 polling semantics.
+    ///
+    /// See [RFC 8628, section 3.5](http://tools.ietf.org/html/rfc8628#section-3.5).
+    pub fn poll_device_token(
+        &self,
+        http_client: &HttpClient,
+        device_code: &DeviceCode,
+    ) -> Result<P::Token, Error> {
+        let mut interval = device_code.interval();
+
+        loop {
+            thread::sleep(StdDuration::from_secs(interval as u64));
+
+            let body = self.token_request_body(&[
+                ("grant_type", GRANT_TYPE),
+                ("device_code", device_code.device_code()),
+            ]);
+            let json = try!(self.post_token(http_client, body));
+
+            match OAuthError::from_response(&json) {
+                Some(result) => {
+                    let error = try!(result);
+                    match *error.error() {
+                        OAuthErrorCode::Other(ref code) if code == "authorization_pending" => continue,
+                        OAuthErrorCode::Other(ref code) if code == "slow_down" => {
+                            interval += 5;
+                            continue;
+                        }
+                        _ => return Err(Error::Parse(ParseError::OAuth(error))),
+                    }
+                }
+                None => return Ok(try!(FromResponse::from_response(&json))),
+            }
+        }
+    }
+}
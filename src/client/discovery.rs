@@ -0,0 +1,164 @@
+//! Authorization Server Metadata discovery.
+//!
+//! See [RFC 8414](http://tools.ietf.org/html/rfc8414).
+
+use std::io::Read;
+use std::marker::PhantomData;
+
+use hyper::client::Client as HttpClient;
+use rustc_serialize::json::Json;
+
+use client::Error;
+use client::response::{FromResponse, JsonHelper, ObjectHelper, ParseError};
+use provider::Provider;
+use token::{Lifetime, Token};
+
+/// Authorization server metadata.
+///
+/// See [RFC 8414, section 2](http://tools.ietf.org/html/rfc8414#section-2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    introspection_endpoint: Option<String>,
+    revocation_endpoint: Option<String>,
+    device_authorization_endpoint: Option<String>,
+    scopes_supported: Option<Vec<String>>,
+    grant_types_supported: Option<Vec<String>>,
+}
+
+impl Metadata {
+    /// Returns the issuer identifier.
+    pub fn issuer(&self) -> &str { &self.issuer }
+
+    /// Returns the authorization endpoint URI.
+    pub fn authorization_endpoint(&self) -> &str { &self.authorization_endpoint }
+
+    /// Returns the token endpoint URI.
+    pub fn token_endpoint(&self) -> &str { &self.token_endpoint }
+
+    /// Returns the token introspection endpoint URI, if advertised.
+    pub fn introspection_endpoint(&self) -> Option<&str> {
+        self.introspection_endpoint.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns the token revocation endpoint URI, if advertised.
+    pub fn revocation_endpoint(&self) -> Option<&str> {
+        self.revocation_endpoint.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns the device authorization endpoint URI, if advertised.
+    pub fn device_authorization_endpoint(&self) -> Option<&str> {
+        self.device_authorization_endpoint.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns the scopes the server supports, if advertised.
+    pub fn scopes_supported(&self) -> Option<&[String]> {
+        self.scopes_supported.as_ref().map(|v| &v[..])
+    }
+
+    /// Returns the grant types the server supports, if advertised.
+    pub fn grant_types_supported(&self) -> Option<&[String]> {
+        self.grant_types_supported.as_ref().map(|v| &v[..])
+    }
+}
+
+fn string_array(obj: &::rustc_serialize::json::Object, field: &str) -> Option<Vec<String>> {
+    obj.get(field).and_then(Json::as_array).map(|values| {
+        values.iter().filter_map(Json::as_string).map(String::from).collect()
+    })
+}
+
+impl FromResponse for Metadata {
+    fn from_response(json: &Json) -> Result<Self, ParseError> {
+        let obj = try!(JsonHelper(json).as_object());
+
+        Ok(Metadata {
+            issuer: try!(obj.get_string("issuer")).into(),
+            authorization_endpoint: try!(obj.get_string("authorization_endpoint")).into(),
+            token_endpoint: try!(obj.get_string("token_endpoint")).into(),
+            introspection_endpoint: obj.get_string_option("introspection_endpoint").map(Into::into),
+            revocation_endpoint: obj.get_string_option("revocation_endpoint").map(Into::into),
+            device_authorization_endpoint:
+                obj.get_string_option("device_authorization_endpoint").map(Into::into),
+            scopes_supported: string_array(obj, "scopes_supported"),
+            grant_types_supported: string_array(obj, "grant_types_supported"),
+        })
+    }
+}
+
+/// Fetches and parses the metadata document at `uri`.
+///
+/// Returns `Ok(None)` if the server responded `404 Not Found`, so that callers can distinguish
+/// "no document published at this well-known path" (expected, and worth falling back from) from
+/// a genuine network or parse failure (not expected, and worth propagating).
+fn fetch_metadata(http_client: &HttpClient, uri: &str) -> Result<Option<Metadata>, Error> {
+    let mut response = try!(http_client.get(uri).send());
+    if response.status == ::hyper::status::StatusCode::NotFound {
+        return Ok(None);
+    }
+
+    let mut body = String::new();
+    try!(response.read_to_string(&mut body));
+    let json = try!(Json::from_str(&body));
+    Ok(Some(try!(Metadata::from_response(&json))))
+}
+
+/// Fetches and parses the authorization server metadata document for the given issuer.
+///
+/// Tries the OAuth 2.0 well-known path first
+/// ([RFC 8414, section 3](http://tools.ietf.org/html/rfc8414#section-3)); if the server responds
+/// `404 Not Found` there, falls back to the OpenID Connect Discovery well-known path, since many
+/// servers only publish the latter. Any other error (network failure, malformed document) is
+/// propagated immediately rather than being masked by the fallback.
+pub fn discover(http_client: &HttpClient, issuer: &str) -> Result<Metadata, Error> {
+    let issuer = issuer.trim_right_matches('/');
+
+    let oauth_uri = format!("{}/.well-known/oauth-authorization-server", issuer);
+    if let Some(metadata) = try!(fetch_metadata(http_client, &oauth_uri)) {
+        return Ok(metadata);
+    }
+
+    let oidc_uri = format!("{}/.well-known/openid-configuration", issuer);
+    match try!(fetch_metadata(http_client, &oidc_uri)) {
+        Some(metadata) => Ok(metadata),
+        None => Err(Error::NotFound),
+    }
+}
+
+/// A provider whose endpoints are discovered at runtime from an issuer's metadata document,
+/// rather than hardcoded as `&'static str`.
+///
+/// `L` and `T` pin down the token lifetime and token type that the discovered provider's token
+/// endpoint is expected to return, since the metadata document itself doesn't carry that
+/// information. Implements `Provider`, so a `DiscoveredProvider` can be used with `Client` in
+/// exactly the same way as a hardcoded provider like `Google`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredProvider<L: Lifetime, T: Token<L>> {
+    metadata: Metadata,
+    marker: PhantomData<(L, T)>,
+}
+
+impl<L: Lifetime, T: Token<L>> DiscoveredProvider<L, T> {
+    /// Discovers a provider's endpoints from its issuer URL.
+    pub fn discover(http_client: &HttpClient, issuer: &str) -> Result<Self, Error> {
+        Ok(DiscoveredProvider {
+            metadata: try!(discover(http_client, issuer)),
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the discovered metadata document.
+    pub fn metadata(&self) -> &Metadata { &self.metadata }
+}
+
+impl<L: Lifetime, T: Token<L>> Provider for DiscoveredProvider<L, T> {
+    type Lifetime = L;
+    type Token = T;
+
+    fn auth_uri(&self) -> &str { self.metadata.authorization_endpoint() }
+    fn token_uri(&self) -> &str { self.metadata.token_endpoint() }
+    fn introspection_uri(&self) -> Option<&str> { self.metadata.introspection_endpoint() }
+    fn device_uri(&self) -> Option<&str> { self.metadata.device_authorization_endpoint() }
+}
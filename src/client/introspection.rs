@@ -0,0 +1,140 @@
+//! Token introspection.
+//!
+//! See [RFC 7662](http://tools.ietf.org/html/rfc7662).
+
+use std::io::Read;
+
+use hyper::client::Client as HttpClient;
+use hyper::header::{Accept, Authorization, Basic, ContentType};
+use rustc_serialize::json::Json;
+use url::form_urlencoded;
+
+use client::{Client, Error};
+use client::response::{FromResponse, JsonHelper, ObjectHelper, ParseError};
+use provider::{Provider, ClientAuthMethod};
+
+/// The result of introspecting a token.
+///
+/// See [RFC 7662, section 2.2](http://tools.ietf.org/html/rfc7662#section-2.2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Introspected {
+    active: bool,
+    scope: Option<String>,
+    client_id: Option<String>,
+    username: Option<String>,
+    token_type: Option<String>,
+    exp: Option<i64>,
+    iat: Option<i64>,
+    nbf: Option<i64>,
+    sub: Option<String>,
+    aud: Option<String>,
+    iss: Option<String>,
+}
+
+impl Introspected {
+    /// Returns whether the token is currently active.
+    ///
+    /// A token that is expired, revoked, malformed or otherwise invalid is inactive, rather than
+    /// an error.
+    pub fn active(&self) -> bool { self.active }
+
+    /// Returns the scope associated with the token, if provided.
+    pub fn scope(&self) -> Option<&str> { self.scope.as_ref().map(|s| &s[..]) }
+
+    /// Returns the client ID the token was issued to, if provided.
+    pub fn client_id(&self) -> Option<&str> { self.client_id.as_ref().map(|s| &s[..]) }
+
+    /// Returns the resource owner's username, if provided.
+    pub fn username(&self) -> Option<&str> { self.username.as_ref().map(|s| &s[..]) }
+
+    /// Returns the token type, if provided.
+    pub fn token_type(&self) -> Option<&str> { self.token_type.as_ref().map(|s| &s[..]) }
+
+    /// Returns the expiration time, as seconds since the epoch, if provided.
+    pub fn exp(&self) -> Option<i64> { self.exp }
+
+    /// Returns the issued-at time, as seconds since the epoch, if provided.
+    pub fn iat(&self) -> Option<i64> { self.iat }
+
+    /// Returns the not-before time, as seconds since the epoch, if provided.
+    pub fn nbf(&self) -> Option<i64> { self.nbf }
+
+    /// Returns the subject of the token, if provided.
+    pub fn sub(&self) -> Option<&str> { self.sub.as_ref().map(|s| &s[..]) }
+
+    /// Returns the intended audience of the token, if provided.
+    pub fn aud(&self) -> Option<&str> { self.aud.as_ref().map(|s| &s[..]) }
+
+    /// Returns the issuer of the token, if provided.
+    pub fn iss(&self) -> Option<&str> { self.iss.as_ref().map(|s| &s[..]) }
+}
+
+impl FromResponse for Introspected {
+    fn from_response(json: &Json) -> Result<Self, ParseError> {
+        let obj = try!(JsonHelper(json).as_object());
+
+        let active = try!(
+            obj.get("active")
+                .and_then(Json::as_boolean)
+                .ok_or(ParseError::ExpectedField("active"))
+        );
+
+        Ok(Introspected {
+            active: active,
+            scope: obj.get_string_option("scope").map(Into::into),
+            client_id: obj.get_string_option("client_id").map(Into::into),
+            username: obj.get_string_option("username").map(Into::into),
+            token_type: obj.get_string_option("token_type").map(Into::into),
+            exp: obj.get_i64_option("exp"),
+            iat: obj.get_i64_option("iat"),
+            nbf: obj.get_i64_option("nbf"),
+            sub: obj.get_string_option("sub").map(Into::into),
+            aud: obj.get_string_option("aud").map(Into::into),
+            iss: obj.get_string_option("iss").map(Into::into),
+        })
+    }
+}
+
+impl<P: Provider> Client<P> {
+    /// Introspects an access token, to check whether it is currently active.
+    ///
+    /// See [RFC 7662, section 2.1](http://tools.ietf.org/html/rfc7662#section-2.1).
+    pub fn introspect(&self, http_client: &HttpClient, token: &str) -> Result<Introspected, Error> {
+        let introspection_uri = match self.provider.introspection_uri() {
+            Some(uri) => uri,
+            None => return Err(Error::Parse(ParseError::ExpectedField("introspection_endpoint"))),
+        };
+
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        serializer.append_pair("client_id", &self.client_id);
+        serializer.append_pair("token", token);
+
+        let mut request = http_client.post(introspection_uri)
+            .header(ContentType::form_url_encoded())
+            .header(Accept::json());
+
+        match self.provider.token_endpoint_auth_method() {
+            ClientAuthMethod::ClientSecretBasic => {
+                request = request.header(Authorization(Basic {
+                    username: self.client_id.clone(),
+                    password: self.client_secret.clone(),
+                }));
+            }
+            ClientAuthMethod::ClientSecretPost => {
+                if let Some(ref client_secret) = self.client_secret {
+                    serializer.append_pair("client_secret", client_secret);
+                }
+            }
+            ClientAuthMethod::None => {}
+        }
+
+        let body = serializer.finish();
+
+        let mut response = try!(request.body(&body).send());
+        let mut response_body = String::new();
+        try!(response.read_to_string(&mut response_body));
+
+        let json = try!(Json::from_str(&response_body));
+        Ok(try!(Introspected::from_response(&json)))
+    }
+}
@@ -0,0 +1,266 @@
+//! Clients.
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::Read;
+
+use hyper::client::Client as HttpClient;
+use hyper::header::{Accept, Authorization, Basic, ContentType};
+use rustc_serialize::json::Json;
+use url::Url;
+use url::form_urlencoded;
+
+use provider::{Provider, ClientAuthMethod};
+use token::{Token, Lifetime, Scopes};
+
+pub mod device;
+pub mod discovery;
+pub mod introspection;
+pub mod pkce;
+pub mod response;
+
+use self::pkce::PkceChallenge;
+use self::response::{FromResponse, ParseError};
+
+/// An OAuth 2.0 client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client<P: Provider> {
+    /// The client ID.
+    pub client_id: String,
+
+    /// The client secret.
+    ///
+    /// `None` for a public client, i.e. one whose `token_endpoint_auth_method` is
+    /// `ClientAuthMethod::None` (typically combined with PKCE).
+    pub client_secret: Option<String>,
+
+    /// The redirection endpoint URI, if applicable.
+    ///
+    /// See [RFC 6749, section 3.1.2](http://tools.ietf.org/html/rfc6749#section-3.1.2).
+    pub redirect_uri: Option<String>,
+
+    provider: P,
+}
+
+/// Errors that can occur while making a request to a provider.
+#[derive(Debug)]
+pub enum Error {
+    /// An error occurred parsing the URI of a provider endpoint.
+    Url(::url::ParseError),
+
+    /// An error occurred making the HTTP request.
+    Http(::hyper::Error),
+
+    /// An error occurred parsing the response body as JSON.
+    Json(::rustc_serialize::json::ParserError),
+
+    /// An error occurred parsing a provider response (token, introspection, device code or
+    /// metadata), or the provider does not have the endpoint that the request needs.
+    Parse(ParseError),
+
+    /// No document was found at the requested well-known discovery path(s).
+    ///
+    /// See [`discovery::discover`](discovery/fn.discover.html).
+    NotFound,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Url(ref error) => write!(f, "{}", error),
+            Error::Http(ref error) => write!(f, "{}", error),
+            Error::Json(ref error) => write!(f, "{}", error),
+            Error::Parse(ref error) => write!(f, "{}", error),
+            Error::NotFound => write!(f, "no metadata document found at the well-known discovery path"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn description(&self) -> &str { "error during OAuth 2.0 request" }
+}
+
+impl From<::url::ParseError> for Error {
+    fn from(error: ::url::ParseError) -> Self { Error::Url(error) }
+}
+
+impl From<::hyper::Error> for Error {
+    fn from(error: ::hyper::Error) -> Self { Error::Http(error) }
+}
+
+impl From<::rustc_serialize::json::ParserError> for Error {
+    fn from(error: ::rustc_serialize::json::ParserError) -> Self { Error::Json(error) }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self { Error::Parse(error) }
+}
+
+impl<P: Provider> Client<P> {
+    /// Creates a client.
+    pub fn new(
+        provider: P,
+        client_id: String,
+        client_secret: Option<String>,
+        redirect_uri: Option<String>,
+    ) -> Self {
+        Client {
+            client_id: client_id,
+            client_secret: client_secret,
+            redirect_uri: redirect_uri,
+            provider: provider,
+        }
+    }
+
+    /// Builds the URI that a user should be redirected to to begin the authorization code grant
+    /// flow.
+    ///
+    /// See [RFC 6749, section 4.1.1](http://tools.ietf.org/html/rfc6749#section-4.1.1).
+    pub fn auth_uri(&self, scope: Option<&Scopes>, state: Option<&str>) -> Result<Url, Error> {
+        self.auth_uri_with_pkce(scope, state, None)
+    }
+
+    /// Builds the authorization URI, additionally binding the request to a PKCE
+    /// `code_challenge`.
+    ///
+    /// See [RFC 7636, section 4.3](http://tools.ietf.org/html/rfc7636#section-4.3).
+    pub fn auth_uri_with_pkce(
+        &self,
+        scope: Option<&Scopes>,
+        state: Option<&str>,
+        pkce: Option<&PkceChallenge>,
+    ) -> Result<Url, Error> {
+        let mut uri = try!(Url::parse(self.provider.auth_uri()));
+
+        {
+            let mut query = uri.query_pairs_mut();
+            query.append_pair("response_type", "code");
+            query.append_pair("client_id", &self.client_id);
+
+            if let Some(ref redirect_uri) = self.redirect_uri {
+                query.append_pair("redirect_uri", redirect_uri);
+            }
+            if let Some(scope) = scope {
+                query.append_pair("scope", scope.as_str());
+            }
+            if let Some(state) = state {
+                query.append_pair("state", state);
+            }
+            if let Some(pkce) = pkce {
+                query.append_pair("code_challenge", pkce.code_challenge());
+                query.append_pair("code_challenge_method", pkce.method_str());
+            }
+        }
+
+        Ok(uri)
+    }
+
+    fn post_token(&self, http_client: &HttpClient, body: String) -> Result<Json, Error> {
+        // Without an explicit Accept header, some providers (e.g. GitHub) default to returning
+        // `application/x-www-form-urlencoded` rather than JSON.
+        let mut request = http_client.post(self.provider.token_uri())
+            .header(ContentType::form_url_encoded())
+            .header(Accept::json());
+
+        if let ClientAuthMethod::ClientSecretBasic = self.provider.token_endpoint_auth_method() {
+            request = request.header(Authorization(Basic {
+                username: self.client_id.clone(),
+                password: self.client_secret.clone(),
+            }));
+        }
+        request = request.body(&body);
+
+        // A non-200 status is not treated as an error here: the token endpoint may still return
+        // a parseable RFC 6749 §5.2 error body, which `FromResponse` detects.
+        let mut response = try!(request.send());
+        let mut body = String::new();
+        try!(response.read_to_string(&mut body));
+
+        Ok(try!(Json::from_str(&body)))
+    }
+
+    fn token_request_body(&self, pairs: &[(&str, &str)]) -> String {
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        serializer.append_pair("client_id", &self.client_id);
+
+        if let ClientAuthMethod::ClientSecretPost = self.provider.token_endpoint_auth_method() {
+            if let Some(ref client_secret) = self.client_secret {
+                serializer.append_pair("client_secret", client_secret);
+            }
+        }
+        if let Some(ref redirect_uri) = self.redirect_uri {
+            serializer.append_pair("redirect_uri", redirect_uri);
+        }
+        for &(key, value) in pairs {
+            serializer.append_pair(key, value);
+        }
+
+        serializer.finish()
+    }
+
+    /// Requests an access token using the authorization code grant.
+    ///
+    /// See [RFC 6749, section 4.1.3](http://tools.ietf.org/html/rfc6749#section-4.1.3).
+    pub fn request_token(&self, http_client: &HttpClient, code: &str) -> Result<P::Token, Error> {
+        self.request_token_with_pkce(http_client, code, None)
+    }
+
+    /// Requests an access token using the authorization code grant, presenting the PKCE
+    /// `code_verifier` that matches the `code_challenge` sent to the authorization endpoint.
+    ///
+    /// See [RFC 7636, section 4.5](http://tools.ietf.org/html/rfc7636#section-4.5).
+    pub fn request_token_with_pkce(
+        &self,
+        http_client: &HttpClient,
+        code: &str,
+        pkce: Option<&PkceChallenge>,
+    ) -> Result<P::Token, Error> {
+        let mut pairs = vec![("grant_type", "authorization_code"), ("code", code)];
+        if let Some(pkce) = pkce {
+            pairs.push(("code_verifier", pkce.code_verifier()));
+        }
+
+        let body = self.token_request_body(&pairs);
+        let json = try!(self.post_token(http_client, body));
+        Ok(try!(FromResponse::from_response(&json)))
+    }
+
+    /// Refreshes an access token.
+    ///
+    /// See [RFC 6749, section 6](http://tools.ietf.org/html/rfc6749#section-6).
+    pub fn refresh_token(
+        &self,
+        http_client: &HttpClient,
+        token: P::Token,
+        scope: Option<&Scopes>,
+    ) -> Result<P::Token, Error>
+        where P::Lifetime: RefreshLifetime
+    {
+        let refresh_token = token.lifetime().refresh_token().to_owned();
+        let mut pairs = vec![("grant_type", "refresh_token"), ("refresh_token", &refresh_token[..])];
+        if let Some(scope) = scope {
+            pairs.push(("scope", scope.as_str()));
+        }
+
+        let body = self.token_request_body(&pairs);
+        let json = try!(self.post_token(http_client, body));
+        Ok(try!(FromResponse::from_response_inherit(&json, &token)))
+    }
+
+    /// Ensures that the given token has not expired, refreshing it if necessary.
+    pub fn ensure_token(&self, http_client: &HttpClient, token: P::Token) -> Result<P::Token, Error>
+        where P::Lifetime: RefreshLifetime
+    {
+        if token.lifetime().expired() {
+            self.refresh_token(http_client, token, None)
+        } else {
+            Ok(token)
+        }
+    }
+}
+
+/// A token lifetime that carries a refresh token.
+pub trait RefreshLifetime: Lifetime {
+    /// Returns the refresh token.
+    fn refresh_token(&self) -> &str;
+}
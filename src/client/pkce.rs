@@ -0,0 +1,87 @@
+//! Proof Key for Code Exchange (PKCE).
+//!
+//! See [RFC 7636](http://tools.ietf.org/html/rfc7636).
+
+use rustc_serialize::base64::{Config, CharacterSet, Newline, ToBase64};
+use rand::{self, Rng};
+use sha2::{Digest, Sha256};
+
+const URL_SAFE_NO_PAD: Config = Config {
+    char_set: CharacterSet::UrlSafe,
+    newline: Newline::LF,
+    pad: false,
+    line_length: None,
+};
+
+/// The method used to derive a `code_challenge` from a `code_verifier`.
+///
+/// See [RFC 7636, section 4.2](http://tools.ietf.org/html/rfc7636#section-4.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PkceMethod {
+    /// `code_challenge = BASE64URL-ENCODE(SHA256(code_verifier))`
+    S256,
+
+    /// `code_challenge = code_verifier`
+    ///
+    /// Included for providers that do not support `S256`, but should be avoided when possible.
+    Plain,
+}
+
+impl PkceMethod {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            PkceMethod::S256 => "S256",
+            PkceMethod::Plain => "plain",
+        }
+    }
+}
+
+const VERIFIER_CHARS: &'static [u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+
+/// A PKCE `code_verifier` and its derived `code_challenge`.
+///
+/// See [RFC 7636, section 4.1](http://tools.ietf.org/html/rfc7636#section-4.1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PkceChallenge {
+    code_verifier: String,
+    code_challenge: String,
+    method: PkceMethod,
+}
+
+impl PkceChallenge {
+    /// Generates a new high-entropy `code_verifier` and derives the `code_challenge` using the
+    /// given method.
+    pub fn new(method: PkceMethod) -> Self {
+        let mut rng = rand::thread_rng();
+        let code_verifier: String = (0..128)
+            .map(|_| VERIFIER_CHARS[rng.gen_range(0, VERIFIER_CHARS.len())] as char)
+            .collect();
+
+        let code_challenge = match method {
+            PkceMethod::S256 => {
+                let digest = Sha256::digest(code_verifier.as_bytes());
+                digest.to_base64(URL_SAFE_NO_PAD)
+            }
+            PkceMethod::Plain => code_verifier.clone(),
+        };
+
+        PkceChallenge {
+            code_verifier: code_verifier,
+            code_challenge: code_challenge,
+            method: method,
+        }
+    }
+
+    /// Returns the `code_verifier`, to be sent in the token request.
+    pub fn code_verifier(&self) -> &str { &self.code_verifier }
+
+    /// Returns the `code_challenge`, to be sent in the authorization request.
+    pub fn code_challenge(&self) -> &str { &self.code_challenge }
+
+    /// Returns the method used to derive the `code_challenge`.
+    pub fn method(&self) -> PkceMethod { self.method }
+
+    /// Returns the value to send as the `code_challenge_method` query parameter.
+    pub fn method_str(&self) -> &'static str { self.method.as_str() }
+}
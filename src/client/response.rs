@@ -0,0 +1,226 @@
+//! Parsing of token endpoint responses.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use rustc_serialize::json::Json;
+
+/// A type that can be parsed from a token endpoint JSON response.
+pub trait FromResponse: Sized {
+    /// Parses the type from a JSON response.
+    fn from_response(json: &Json) -> Result<Self, ParseError>;
+
+    /// Parses the type from a JSON response, inheriting fields omitted from
+    /// the response (e.g. an absent `refresh_token` on a refresh) from a
+    /// previous value.
+    ///
+    /// The default implementation ignores `prev` and is equivalent to
+    /// `from_response`.
+    fn from_response_inherit(json: &Json, _prev: &Self) -> Result<Self, ParseError> {
+        Self::from_response(json)
+    }
+}
+
+/// Errors encountered while parsing a token endpoint response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The response was not a JSON object.
+    ExpectedType(&'static str),
+
+    /// A required field was missing.
+    ExpectedField(&'static str),
+
+    /// A field did not have the expected value.
+    ExpectedFieldValue(&'static str, &'static str),
+
+    /// The response was a well-formed [RFC 6749 §5.2](http://tools.ietf.org/html/rfc6749#section-5.2)
+    /// error response.
+    OAuth(OAuthError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::ExpectedType(t) => write!(f, "expected {}", t),
+            ParseError::ExpectedField(field) => write!(f, "expected field '{}'", field),
+            ParseError::ExpectedFieldValue(field, value) =>
+                write!(f, "expected '{}' for field '{}'", value, field),
+            ParseError::OAuth(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl StdError for ParseError {
+    fn description(&self) -> &str { "error parsing response" }
+}
+
+/// Helper for reading fields out of a JSON object.
+pub struct JsonHelper<'a>(pub &'a Json);
+
+impl<'a> JsonHelper<'a> {
+    /// Returns the underlying object, or an error if the JSON value is not
+    /// an object.
+    pub fn as_object(&self) -> Result<&'a ::rustc_serialize::json::Object, ParseError> {
+        self.0.as_object().ok_or(ParseError::ExpectedType("object"))
+    }
+}
+
+/// Extension methods for reading typed fields out of a JSON object.
+pub trait ObjectHelper {
+    /// Returns a required string field.
+    fn get_string(&self, field: &'static str) -> Result<&str, ParseError>;
+
+    /// Returns an optional string field.
+    fn get_string_option(&self, field: &'static str) -> Option<&str>;
+
+    /// Returns a required integer field.
+    fn get_i64(&self, field: &'static str) -> Result<i64, ParseError>;
+
+    /// Returns an optional integer field.
+    fn get_i64_option(&self, field: &'static str) -> Option<i64>;
+}
+
+impl ObjectHelper for ::rustc_serialize::json::Object {
+    fn get_string(&self, field: &'static str) -> Result<&str, ParseError> {
+        self.get(field)
+            .and_then(Json::as_string)
+            .ok_or(ParseError::ExpectedField(field))
+    }
+
+    fn get_string_option(&self, field: &'static str) -> Option<&str> {
+        self.get(field).and_then(Json::as_string)
+    }
+
+    fn get_i64(&self, field: &'static str) -> Result<i64, ParseError> {
+        self.get(field)
+            .and_then(Json::as_i64)
+            .ok_or(ParseError::ExpectedField(field))
+    }
+
+    fn get_i64_option(&self, field: &'static str) -> Option<i64> {
+        self.get(field).and_then(Json::as_i64)
+    }
+}
+
+/// An [RFC 6749 §5.2](http://tools.ietf.org/html/rfc6749#section-5.2) error code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OAuthErrorCode {
+    /// `invalid_request`
+    InvalidRequest,
+
+    /// `invalid_client`
+    InvalidClient,
+
+    /// `invalid_grant`
+    InvalidGrant,
+
+    /// `unauthorized_client`
+    UnauthorizedClient,
+
+    /// `unsupported_grant_type`
+    UnsupportedGrantType,
+
+    /// `invalid_scope`
+    InvalidScope,
+
+    /// Any other error code not defined by the RFC.
+    Other(String),
+}
+
+impl<'a> From<&'a str> for OAuthErrorCode {
+    fn from(code: &'a str) -> Self {
+        match code {
+            "invalid_request" => OAuthErrorCode::InvalidRequest,
+            "invalid_client" => OAuthErrorCode::InvalidClient,
+            "invalid_grant" => OAuthErrorCode::InvalidGrant,
+            "unauthorized_client" => OAuthErrorCode::UnauthorizedClient,
+            "unsupported_grant_type" => OAuthErrorCode::UnsupportedGrantType,
+            "invalid_scope" => OAuthErrorCode::InvalidScope,
+            other => OAuthErrorCode::Other(other.into()),
+        }
+    }
+}
+
+impl fmt::Display for OAuthErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OAuthErrorCode::InvalidRequest => write!(f, "invalid_request"),
+            OAuthErrorCode::InvalidClient => write!(f, "invalid_client"),
+            OAuthErrorCode::InvalidGrant => write!(f, "invalid_grant"),
+            OAuthErrorCode::UnauthorizedClient => write!(f, "unauthorized_client"),
+            OAuthErrorCode::UnsupportedGrantType => write!(f, "unsupported_grant_type"),
+            OAuthErrorCode::InvalidScope => write!(f, "invalid_scope"),
+            OAuthErrorCode::Other(ref code) => write!(f, "{}", code),
+        }
+    }
+}
+
+/// An [RFC 6749 §5.2](http://tools.ietf.org/html/rfc6749#section-5.2) error response from the
+/// token endpoint.
+///
+/// Returned instead of a token when, for example, an authorization code has already been
+/// redeemed or a refresh token has been revoked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthError {
+    error: OAuthErrorCode,
+    error_description: Option<String>,
+    error_uri: Option<String>,
+}
+
+impl OAuthError {
+    /// Parses an error response from the given JSON value, if it has an `error` field.
+    ///
+    /// Returns `None` if the response does not look like an error response, so callers can fall
+    /// back to parsing a successful response.
+    pub fn from_response(json: &Json) -> Option<Result<Self, ParseError>> {
+        let obj = match json.as_object() {
+            Some(obj) => obj,
+            None => return None,
+        };
+        if !obj.contains_key("error") {
+            return None;
+        }
+
+        Some(Self::parse(obj))
+    }
+
+    fn parse(obj: &::rustc_serialize::json::Object) -> Result<Self, ParseError> {
+        let error = try!(obj.get_string("error"));
+        Ok(OAuthError {
+            error: OAuthErrorCode::from(error),
+            error_description: obj.get_string_option("error_description").map(Into::into),
+            error_uri: obj.get_string_option("error_uri").map(Into::into),
+        })
+    }
+
+    /// Returns the error code.
+    pub fn error(&self) -> &OAuthErrorCode { &self.error }
+
+    /// Returns the human-readable error description, if provided.
+    pub fn error_description(&self) -> Option<&str> {
+        self.error_description.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns a URI identifying a human-readable web page with information about the error, if
+    /// provided.
+    pub fn error_uri(&self) -> Option<&str> {
+        self.error_uri.as_ref().map(|s| &s[..])
+    }
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{}", self.error));
+        if let Some(description) = self.error_description() {
+            try!(write!(f, ": {}", description));
+        }
+        if let Some(uri) = self.error_uri() {
+            try!(write!(f, " (see {})", uri));
+        }
+        Ok(())
+    }
+}
+
+impl StdError for OAuthError {
+    fn description(&self) -> &str { "OAuth error response" }
+}
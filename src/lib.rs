@@ -0,0 +1,22 @@
+//! OAuth 2.0 client library.
+//!
+//! See [RFC 6749](http://tools.ietf.org/html/rfc6749).
+
+#![warn(missing_docs)]
+
+extern crate chrono;
+extern crate hyper;
+extern crate rand;
+extern crate rustc_serialize;
+extern crate serde;
+extern crate serde_json;
+extern crate sha2;
+extern crate url;
+
+pub use client::Client;
+pub use provider::{Provider, ClientAuthMethod};
+pub use token::{Token, Lifetime};
+
+pub mod client;
+pub mod provider;
+pub mod token;
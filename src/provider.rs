@@ -2,7 +2,35 @@
 
 use token::{Token, Lifetime, Bearer, Static, Expiring};
 
+/// How a client authenticates itself to the token endpoint.
+///
+/// See [RFC 6749, section 2.3](http://tools.ietf.org/html/rfc6749#section-2.3) and the
+/// `token_endpoint_auth_method` vocabulary in
+/// [RFC 8414, section 2](http://tools.ietf.org/html/rfc8414#section-2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientAuthMethod {
+    /// `client_secret_basic`: `client_id`/`client_secret` sent as HTTP Basic credentials.
+    ///
+    /// The default, and the method recommended by the RFC.
+    ClientSecretBasic,
+
+    /// `client_secret_post`: `client_id`/`client_secret` sent as part of the request body.
+    ///
+    /// Not recommended by the RFC, but required by some providers.
+    ClientSecretPost,
+
+    /// `none`: no client secret is sent.
+    ///
+    /// For public clients (native/mobile apps, SPAs) that cannot keep a secret confidential,
+    /// typically combined with PKCE.
+    None,
+}
+
 /// OAuth 2.0 providers.
+///
+/// Endpoint methods take `&self` and return a borrowed `&str`, rather than being associated
+/// functions returning `&'static str`, so that a provider's endpoints can be discovered at
+/// runtime (see `client::discovery::DiscoveredProvider`) as well as hardcoded.
 pub trait Provider {
     /// The lifetime of tokens issued by the provider.
     type Lifetime: Lifetime;
@@ -13,26 +41,28 @@ pub trait Provider {
     /// The authorization endpoint URI.
     ///
     /// See [RFC 6749, section 3.1](http://tools.ietf.org/html/rfc6749#section-3.1).
-    ///
-    /// Note: likely to become an associated constant.
-    fn auth_uri() -> &'static str;
+    fn auth_uri(&self) -> &str;
 
     /// The token endpoint URI.
     ///
     /// See [RFC 6749, section 3.2](http://tools.ietf.org/html/rfc6749#section-3.2).
-    ///
-    /// Note: likely to become an associated constant.
-    fn token_uri() -> &'static str;
+    fn token_uri(&self) -> &str;
 
-    /// Provider requires credentials via request body.
+    /// The method used to authenticate the client to the token endpoint.
     ///
-    /// Although not recommended by the RFC, some providers require `client_id` and `client_secret`
-    /// as part of the request body.
+    /// See [RFC 6749, section 2.3](http://tools.ietf.org/html/rfc6749#section-2.3).
+    fn token_endpoint_auth_method(&self) -> ClientAuthMethod { ClientAuthMethod::ClientSecretBasic }
+
+    /// The device authorization endpoint URI, if the provider supports the device authorization
+    /// grant.
     ///
-    /// See [RFC 6749, section 2.3.1](http://tools.ietf.org/html/rfc6749#section-2.3.1).
+    /// See [RFC 8628, section 3.1](http://tools.ietf.org/html/rfc8628#section-3.1).
+    fn device_uri(&self) -> Option<&str> { None }
+
+    /// The token introspection endpoint URI, if the provider supports it.
     ///
-    /// Note: likely to become an associated constant.
-    fn credentials_in_body() -> bool { false }
+    /// See [RFC 7662, section 2](http://tools.ietf.org/html/rfc7662#section-2).
+    fn introspection_uri(&self) -> Option<&str> { None }
 }
 
 /// Google OAuth 2.0 provider.
@@ -44,8 +74,9 @@ pub struct Google;
 impl Provider for Google {
     type Lifetime = Expiring;
     type Token = Bearer<Expiring>;
-    fn auth_uri() -> &'static str { "https://accounts.google.com/o/oauth2/v2/auth" }
-    fn token_uri() -> &'static str { "https://www.googleapis.com/oauth2/v4/token" }
+    fn auth_uri(&self) -> &str { "https://accounts.google.com/o/oauth2/v2/auth" }
+    fn token_uri(&self) -> &str { "https://www.googleapis.com/oauth2/v4/token" }
+    fn device_uri(&self) -> Option<&str> { Some("https://oauth2.googleapis.com/device/code") }
 }
 
 /// GitHub OAuth 2.0 provider.
@@ -56,8 +87,8 @@ pub struct GitHub;
 impl Provider for GitHub {
     type Lifetime = Static;
     type Token = Bearer<Static>;
-    fn auth_uri() -> &'static str { "https://github.com/login/oauth/authorize" }
-    fn token_uri() -> &'static str { "https://github.com/login/oauth/access_token" }
+    fn auth_uri(&self) -> &str { "https://github.com/login/oauth/authorize" }
+    fn token_uri(&self) -> &str { "https://github.com/login/oauth/access_token" }
 }
 
 /// Imgur OAuth 2.0 provider.
@@ -68,6 +99,6 @@ pub struct Imgur;
 impl Provider for Imgur {
     type Lifetime = Expiring;
     type Token = Bearer<Expiring>;
-    fn auth_uri() -> &'static str { "https://api.imgur.com/oauth2/authorize" }
-    fn token_uri() -> &'static str { "https://api.imgur.com/oauth2/token" }
+    fn auth_uri(&self) -> &str { "https://api.imgur.com/oauth2/authorize" }
+    fn token_uri(&self) -> &str { "https://api.imgur.com/oauth2/token" }
 }
@@ -5,8 +5,8 @@ use rustc_serialize::json::Json;
 use serde::{Serialize, Serializer, Deserialize, Deserializer};
 use serde::{ser, de};
 
-use super::{Token, Lifetime};
-use client::response::{FromResponse, ParseError, JsonHelper};
+use super::{Token, Lifetime, Scopes};
+use client::response::{FromResponse, ParseError, JsonHelper, ObjectHelper, OAuthError};
 
 /// The bearer token type.
 ///
@@ -14,14 +14,17 @@ use client::response::{FromResponse, ParseError, JsonHelper};
 #[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
 pub struct Bearer<L: Lifetime> {
     access_token: String,
-    scope: Option<String>,
+    scope: Scopes,
     lifetime: L,
     id_token: Option<String>,
 }
 
 impl<L: Lifetime> Token<L> for Bearer<L> {
     fn access_token(&self) -> &str { &self.access_token }
-    fn scope(&self) -> Option<&str> { self.scope.as_ref().map(|s| &s[..]) }
+    fn scope(&self) -> Option<&str> {
+        if self.scope.is_empty() { None } else { Some(self.scope.as_str()) }
+    }
+    fn scopes(&self) -> &Scopes { &self.scope }
     fn lifetime(&self) -> &L { &self.lifetime }
     fn id_token(&self) -> Option<&str> { self.id_token.as_ref().map(|s| &s[..]) }
 }
@@ -42,12 +45,12 @@ impl<L: Lifetime> Bearer<L> {
         }
 
         let access_token = try!(obj.get_string("access_token"));
-        let scope = obj.get_string_option("scope");
+        let scope = obj.get_string_option("scope").map(Scopes::from).unwrap_or_else(Scopes::new);
         let id_token = obj.get_string_option("id_token");
 
         Ok(Bearer {
             access_token: access_token.into(),
-            scope: scope.map(Into::into),
+            scope: scope,
             lifetime: lifetime,
             id_token: id_token.map(Into::into),
         })
@@ -56,11 +59,19 @@ impl<L: Lifetime> Bearer<L> {
 
 impl<L: Lifetime> FromResponse for Bearer<L> {
     fn from_response(json: &Json) -> Result<Self, ParseError> {
+        if let Some(result) = OAuthError::from_response(json) {
+            return Err(ParseError::OAuth(try!(result)));
+        }
+
         let lifetime = try!(FromResponse::from_response(json));
         Bearer::from_response_and_lifetime(json, lifetime)
     }
 
     fn from_response_inherit(json: &Json, prev: &Self) -> Result<Self, ParseError> {
+        if let Some(result) = OAuthError::from_response(json) {
+            return Err(ParseError::OAuth(try!(result)));
+        }
+
         let lifetime = try!(FromResponse::from_response_inherit(json, &prev.lifetime));
         Bearer::from_response_and_lifetime(json, lifetime)
     }
@@ -128,7 +139,7 @@ impl<L: Lifetime + Deserialize> de::Visitor for DeVisitor<L> {
 
         Ok(Bearer {
             access_token: access_token,
-            scope: scope,
+            scope: scope.unwrap_or_else(Scopes::new),
             lifetime: lifetime,
             id_token: id_token,
         })
@@ -169,10 +180,23 @@ mod tests {
     use rustc_serialize::json::Json;
     use serde_json;
 
-    use client::response::{FromResponse, ParseError};
-    use token::{Static, Expiring};
+    use client::response::{FromResponse, ParseError, OAuthErrorCode};
+    use token::{Static, Expiring, Scopes};
     use super::Bearer;
 
+    #[test]
+    fn from_response_with_oauth_error() {
+        let json = Json::from_str(
+            r#"{"error":"invalid_grant","error_description":"refresh token revoked"}"#
+        ).unwrap();
+        let error = match Bearer::<Static>::from_response(&json).unwrap_err() {
+            ParseError::OAuth(error) => error,
+            other => panic!("expected ParseError::OAuth, got {:?}", other),
+        };
+        assert_eq!(&OAuthErrorCode::InvalidGrant, error.error());
+        assert_eq!(Some("refresh token revoked"), error.error_description());
+    }
+
     #[test]
     fn from_response_with_invalid_token_type() {
         let json = Json::from_str(r#"{"token_type":"MAC","access_token":"aaaaaaaa"}"#).unwrap();
@@ -188,7 +212,7 @@ mod tests {
         assert_eq!(
             Bearer {
                 access_token: String::from("aaaaaaaa"),
-                scope: None,
+                scope: Scopes::new(),
                 lifetime: Static,
                 id_token: None,
             },
@@ -202,7 +226,7 @@ mod tests {
         assert_eq!(
             Bearer {
                 access_token: String::from("aaaaaaaa"),
-                scope: None,
+                scope: Scopes::new(),
                 lifetime: Static,
                 id_token: None,
             },
@@ -215,15 +239,17 @@ mod tests {
         let json = Json::from_str(
             r#"{"token_type":"Bearer","access_token":"aaaaaaaa","scope":"foo"}"#
         ).unwrap();
+        let bearer = Bearer::<Static>::from_response(&json).unwrap();
         assert_eq!(
             Bearer {
                 access_token: String::from("aaaaaaaa"),
-                scope: Some(String::from("foo")),
+                scope: Scopes::from("foo"),
                 lifetime: Static,
                 id_token: None,
             },
-            Bearer::<Static>::from_response(&json).unwrap()
+            bearer
         );
+        assert!(bearer.scopes().contains("foo"));
     }
 
     #[test]
@@ -238,7 +264,7 @@ mod tests {
         "#).unwrap();
         let bearer = Bearer::<Expiring>::from_response(&json).unwrap();
         assert_eq!("aaaaaaaa", bearer.access_token);
-        assert_eq!(None, bearer.scope);
+        assert_eq!(Scopes::new(), bearer.scope);
         let expiring = bearer.lifetime;
         assert_eq!("bbbbbbbb", expiring.refresh_token());
         assert!(expiring.expires() > &UTC::now());
@@ -266,7 +292,7 @@ mod tests {
         "#).unwrap();
         let bearer = Bearer::<Expiring>::from_response_inherit(&json, &prev).unwrap();
         assert_eq!("cccccccc", bearer.access_token);
-        assert_eq!(None, bearer.scope);
+        assert_eq!(Scopes::new(), bearer.scope);
         let expiring = bearer.lifetime;
         assert_eq!("bbbbbbbb", expiring.refresh_token());
         assert!(expiring.expires() > &UTC::now());
@@ -277,7 +303,7 @@ mod tests {
     fn serialize_deserialize() {
         let original = Bearer {
             access_token: String::from("foo"),
-            scope: Some(String::from("bar")),
+            scope: Scopes::from("bar"),
             lifetime: Static,
             id_token: Some(String::from("baz")),
         };
@@ -0,0 +1,144 @@
+use chrono::{DateTime, UTC, Duration};
+use rustc_serialize::json::Json;
+use serde::{Serialize, Serializer, Deserialize, Deserializer, ser, de};
+
+use client::response::{FromResponse, ParseError, JsonHelper, ObjectHelper};
+use client::RefreshLifetime;
+use super::Lifetime;
+
+/// An expiring token, with an associated refresh token.
+///
+/// See [RFC 6749, section 4.2.2](http://tools.ietf.org/html/rfc6749#section-4.2.2) and
+/// [section 6](http://tools.ietf.org/html/rfc6749#section-6).
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct Expiring {
+    refresh_token: String,
+    expires: DateTime<UTC>,
+}
+
+impl Expiring {
+    /// Returns the refresh token.
+    pub fn refresh_token(&self) -> &str { &self.refresh_token }
+
+    /// Returns the expiry time.
+    pub fn expires(&self) -> &DateTime<UTC> { &self.expires }
+}
+
+impl Lifetime for Expiring {
+    fn expired(&self) -> bool { self.expires <= UTC::now() }
+}
+
+impl RefreshLifetime for Expiring {
+    fn refresh_token(&self) -> &str { &self.refresh_token }
+}
+
+impl FromResponse for Expiring {
+    fn from_response(json: &Json) -> Result<Self, ParseError> {
+        let obj = try!(JsonHelper(json).as_object());
+        let expires_in = try!(obj.get_i64("expires_in"));
+        let refresh_token = try!(obj.get_string("refresh_token"));
+
+        Ok(Expiring {
+            refresh_token: refresh_token.into(),
+            expires: UTC::now() + Duration::seconds(expires_in),
+        })
+    }
+
+    fn from_response_inherit(json: &Json, prev: &Self) -> Result<Self, ParseError> {
+        let obj = try!(JsonHelper(json).as_object());
+        let expires_in = try!(obj.get_i64("expires_in"));
+        let refresh_token = obj.get_string_option("refresh_token")
+            .map(Into::into)
+            .unwrap_or_else(|| prev.refresh_token.clone());
+
+        Ok(Expiring {
+            refresh_token: refresh_token,
+            expires: UTC::now() + Duration::seconds(expires_in),
+        })
+    }
+}
+
+impl Serialize for Expiring {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        serializer.visit_struct("Expiring", SerVisitor(self, 0))
+    }
+}
+
+struct SerVisitor<'a>(&'a Expiring, u8);
+impl<'a> ser::MapVisitor for SerVisitor<'a> {
+    fn visit<S: Serializer>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error> {
+        self.1 += 1;
+        match self.1 {
+            1 => serializer.visit_struct_elt("refresh_token", &self.0.refresh_token).map(Some),
+            2 => serializer.visit_struct_elt("expires", &self.0.expires).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    fn len(&self) -> Option<usize> { Some(2) }
+}
+
+impl Deserialize for Expiring {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Self, D::Error> {
+        static FIELDS: &'static [&'static str] = &["refresh_token", "expires"];
+        deserializer.visit_struct("Expiring", FIELDS, DeVisitor)
+    }
+}
+
+struct DeVisitor;
+impl de::Visitor for DeVisitor {
+    type Value = Expiring;
+
+    fn visit_map<V: de::MapVisitor>(&mut self, mut visitor: V) -> Result<Expiring, V::Error> {
+        let mut refresh_token = None;
+        let mut expires = None;
+
+        loop {
+            match try!(visitor.visit_key()) {
+                Some(Field::RefreshToken) => refresh_token = Some(try!(visitor.visit_value())),
+                Some(Field::Expires) => expires = Some(try!(visitor.visit_value())),
+                None => break,
+            }
+        }
+
+        let refresh_token = match refresh_token {
+            Some(s) => s,
+            None => return visitor.missing_field("refresh_token"),
+        };
+        let expires = match expires {
+            Some(e) => e,
+            None => return visitor.missing_field("expires"),
+        };
+
+        try!(visitor.end());
+
+        Ok(Expiring {
+            refresh_token: refresh_token,
+            expires: expires,
+        })
+    }
+}
+
+enum Field {
+    RefreshToken,
+    Expires,
+}
+
+impl Deserialize for Field {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.visit(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+impl de::Visitor for FieldVisitor {
+    type Value = Field;
+
+    fn visit_str<E: de::Error>(&mut self, value: &str) -> Result<Field, E> {
+        match value {
+            "refresh_token" => Ok(Field::RefreshToken),
+            "expires" => Ok(Field::Expires),
+            _ => Err(de::Error::syntax("expected refresh_token or expires")),
+        }
+    }
+}
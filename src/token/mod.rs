@@ -19,6 +19,9 @@ pub trait Token<L: Lifetime>: FromResponse {
     /// Returns the scope, if available.
     fn scope(&self) -> Option<&str>;
 
+    /// Returns the scope as a structured `Scopes` set.
+    fn scopes(&self) -> &Scopes;
+
     /// Returns the token lifetime.
     fn lifetime(&self) -> &L;
 
@@ -35,6 +38,9 @@ pub trait Lifetime: FromResponse {
 pub use self::bearer::Bearer;
 mod bearer;
 
+pub use self::scope::{Scope, Scopes};
+mod scope;
+
 pub use self::statik::Static;
 mod statik;
 
@@ -0,0 +1,153 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::slice;
+
+use rustc_serialize::{Encodable, Encoder, Decodable, Decoder};
+use serde::{Serialize, Serializer, Deserialize, Deserializer, de};
+
+/// A single OAuth 2.0 scope.
+///
+/// See [RFC 6749, section 3.3](http://tools.ietf.org/html/rfc6749#section-3.3).
+pub type Scope = String;
+
+/// An ordered set of scopes.
+///
+/// Serializes and deserializes as a single space-delimited string, per
+/// [RFC 6749, section 3.3](http://tools.ietf.org/html/rfc6749#section-3.3), rather than as a
+/// JSON array. Duplicate scopes are dropped, keeping the first occurrence's position.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scopes {
+    joined: String,
+    scopes: Vec<Scope>,
+}
+
+impl Scopes {
+    /// Returns an empty scope set.
+    pub fn new() -> Self { Scopes { joined: String::new(), scopes: Vec::new() } }
+
+    /// Returns true if the scope set contains the given scope.
+    pub fn contains(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+
+    /// Returns true if the scope set is empty.
+    pub fn is_empty(&self) -> bool { self.scopes.is_empty() }
+
+    /// Returns an iterator over the scopes, in the order they were added.
+    pub fn iter(&self) -> slice::Iter<Scope> { self.scopes.iter() }
+
+    /// Returns the space-delimited string form of the scope set.
+    ///
+    /// See [RFC 6749, section 3.3](http://tools.ietf.org/html/rfc6749#section-3.3).
+    pub fn as_str(&self) -> &str { &self.joined }
+}
+
+impl<'a> From<&'a str> for Scopes {
+    fn from(scope: &'a str) -> Self {
+        scope.split(' ').filter(|s| !s.is_empty()).map(String::from).collect()
+    }
+}
+
+impl FromIterator<Scope> for Scopes {
+    fn from_iter<I: IntoIterator<Item = Scope>>(iter: I) -> Self {
+        let mut scopes = Vec::new();
+        for scope in iter {
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+        let joined = scopes.join(" ");
+        Scopes { joined: joined, scopes: scopes }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Scopes {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        iter.into_iter().map(String::from).collect()
+    }
+}
+
+impl fmt::Display for Scopes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.joined)
+    }
+}
+
+impl<'a> IntoIterator for &'a Scopes {
+    type Item = &'a Scope;
+    type IntoIter = slice::Iter<'a, Scope>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl Encodable for Scopes {
+    fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_str(&self.joined)
+    }
+}
+
+impl Decodable for Scopes {
+    fn decode<D: Decoder>(d: &mut D) -> Result<Self, D::Error> {
+        let joined = try!(d.read_str());
+        Ok(Scopes::from(&joined[..]))
+    }
+}
+
+impl Serialize for Scopes {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        serializer.visit_str(&self.joined)
+    }
+}
+
+impl Deserialize for Scopes {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.visit_str(ScopesVisitor)
+    }
+}
+
+struct ScopesVisitor;
+impl de::Visitor for ScopesVisitor {
+    type Value = Scopes;
+
+    fn visit_str<E: de::Error>(&mut self, value: &str) -> Result<Scopes, E> {
+        Ok(Scopes::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scopes;
+
+    #[test]
+    fn from_str_splits_on_space() {
+        let scopes = Scopes::from("repo user:email");
+        assert!(scopes.contains("repo"));
+        assert!(scopes.contains("user:email"));
+        assert!(!scopes.contains("gist"));
+    }
+
+    #[test]
+    fn from_str_ignores_repeated_spaces() {
+        let scopes = Scopes::from("repo  user:email");
+        assert_eq!(2, scopes.iter().count());
+    }
+
+    #[test]
+    fn display_joins_with_space() {
+        let scopes = Scopes::from("repo user:email");
+        assert_eq!("repo user:email", scopes.to_string());
+    }
+
+    #[test]
+    fn as_str_matches_display() {
+        let scopes = Scopes::from("repo user:email");
+        assert_eq!(scopes.to_string(), scopes.as_str());
+    }
+
+    #[test]
+    fn from_iterator_deduplicates() {
+        let scopes: Scopes = vec!["repo", "repo", "gist"].into_iter().collect();
+        assert_eq!(2, scopes.iter().count());
+        assert_eq!("repo gist", scopes.as_str());
+    }
+}
@@ -0,0 +1,32 @@
+use rustc_serialize::json::Json;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+use client::response::{FromResponse, ParseError};
+use super::Lifetime;
+
+/// A non-expiring token, without a refresh token.
+///
+/// See [RFC 6749, section 4.2.2](http://tools.ietf.org/html/rfc6749#section-4.2.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct Static;
+
+impl Serialize for Static {
+    fn serialize<S: Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        serializer.visit_unit_struct("Static")
+    }
+}
+
+impl Deserialize for Static {
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Self, D::Error> {
+        try!(deserializer.visit_unit_struct("Static", ::serde::de::impls::UnitStructVisitor::new()));
+        Ok(Static)
+    }
+}
+
+impl Lifetime for Static {
+    fn expired(&self) -> bool { false }
+}
+
+impl FromResponse for Static {
+    fn from_response(_json: &Json) -> Result<Self, ParseError> { Ok(Static) }
+}